@@ -1,4 +1,5 @@
 use crate::*;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -37,6 +38,32 @@ pub async fn get(client: &Client, address: &str) -> Result<Account> {
         .await
 }
 
+/// Get the state of a specific account as of a given block height,
+/// rather than the latest state known to the API. Returns an error if
+/// the node has already pruned the chain state at that height.
+pub async fn get_at_block(client: &Client, address: &str, block: u64) -> Result<Account> {
+    client
+        .fetch(
+            &format!("/accounts/{}?max_block={}", address, block),
+            NO_QUERY,
+        )
+        .await
+}
+
+/// Get the nonce of a specific account as of a given block height
+pub async fn nonce_at(client: &Client, address: &str, block: u64) -> Result<u64> {
+    let account = get_at_block(client, address, block).await?;
+    Ok(account.nonce)
+}
+
+/// Get the next nonce to use when building a transaction for this
+/// account, accounting for any transactions already in flight
+#[cfg(feature = "transactions")]
+pub async fn next_nonce(client: &Client, address: &str) -> Result<u64> {
+    let account = get(client, address).await?;
+    Ok(std::cmp::max(account.nonce, account.speculative_nonce) + 1)
+}
+
 /// Get all hotspots owned by a given account
 pub fn hotspots(client: &Client, address: &str) -> Stream<hotspots::Hotspot> {
     client.fetch_stream(&format!("/accounts/{}/hotspots", address), NO_QUERY)
@@ -100,6 +127,112 @@ pub fn get_rewards_between(client: &Client, address: &str, min_time: DateTime<Ut
 
 }
 
+/// Get all the rewards for the account between the given times, each
+/// paired with its USD value at the block it was earned, using the
+/// Helium oracle price history. The oracle price series is fetched
+/// once and cached, rather than issuing one request per reward.
+///
+/// This returns a `Vec` rather than a `Stream` like its siblings: the
+/// cache-first join needs the full oracle price series up front, so
+/// there is no streaming variant of this lookup. A reward earlier than
+/// the first known oracle price has no price to join against, and is
+/// returned paired with `None` rather than being dropped.
+#[cfg(feature = "transactions")]
+pub async fn get_rewards_between_valued(
+    client: &Client,
+    address: &str,
+    min_time: DateTime<Utc>,
+    max_time: DateTime<Utc>,
+) -> Result<Vec<(reward::Reward, Option<Decimal>)>> {
+    let rewards = get_rewards_between(client, address, min_time, max_time)
+        .into_vec()
+        .await?;
+    let prices = oracle::PriceHistory::new(client).await?;
+    Ok(rewards
+        .into_iter()
+        .map(|reward| {
+            let usd = prices
+                .price_at(reward.block)
+                .map(|price| price * reward.amount.get_decimal());
+            (reward, usd)
+        })
+        .collect())
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+/// The total rewards earned during a single bucket of a bucketed
+/// reward sum
+pub struct RewardBucket {
+    /// The start of the bucket's time window
+    pub timestamp: DateTime<Utc>,
+    /// The total rewards earned during this bucket
+    #[serde(deserialize_with = "Hnt::deserialize")]
+    pub total: Hnt,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct RewardSumTotal {
+    /// The total rewards earned over the requested range
+    #[serde(deserialize_with = "Hnt::deserialize")]
+    total: Hnt,
+}
+
+#[derive(Clone, Serialize, Debug)]
+/// An aggregated summary of the rewards earned by an account over a
+/// time range. The time range is the one passed to [`reward_sum`]: the
+/// API's `/rewards/sum` response carries it in the response envelope's
+/// `meta`, not alongside `total`, so it is threaded through here rather
+/// than deserialized from the response body.
+pub struct RewardSum {
+    /// The total rewards earned over the requested range. `None` when
+    /// `bucket` was requested: the bucketed response is a plain array
+    /// of per-bucket totals with no separate grand total to deserialize.
+    pub total: Option<Hnt>,
+    /// The start of the requested time range
+    pub min_time: DateTime<Utc>,
+    /// The end of the requested time range
+    pub max_time: DateTime<Utc>,
+    /// The per-bucket totals, present when a `bucket` was requested
+    pub buckets: Vec<RewardBucket>,
+}
+
+/// Get the aggregated reward total for the account over a time range,
+/// optionally bucketed by "day", "week" or "month", rather than
+/// streaming every `Reward` and folding it by hand.
+#[cfg(feature = "transactions")]
+pub async fn reward_sum(
+    client: &Client,
+    address: &str,
+    min_time: DateTime<Utc>,
+    max_time: DateTime<Utc>,
+    bucket: Option<&str>,
+) -> Result<RewardSum> {
+    let mut query = vec![
+        ["max_time".to_string(), format!("{:?}", max_time)],
+        ["min_time".to_string(), format!("{:?}", min_time)],
+    ];
+    if let Some(bucket) = bucket {
+        query.push(["bucket".to_string(), bucket.to_string()]);
+    }
+
+    let path = format!("/accounts/{}/rewards/sum", address);
+
+    let (total, buckets) = if bucket.is_some() {
+        let buckets: Vec<RewardBucket> = client.fetch(&path, &query).await?;
+        (None, buckets)
+    } else {
+        let data: RewardSumTotal = client.fetch(&path, &query).await?;
+        (Some(data.total), Vec::new())
+    };
+
+    Ok(RewardSum {
+        total,
+        min_time,
+        max_time,
+        buckets,
+    })
+}
+
 /// Get a list of of up to a limit (maximum 1000) accounts sorted by their balance in
 /// descending order
 pub async fn richest(client: &Client, limit: Option<u32>) -> Result<Vec<Account>> {
@@ -144,6 +277,86 @@ mod test {
         );
     }
 
+    #[test]
+    async fn get_at_block() {
+        let client = Client::default();
+        let account = accounts::get_at_block(
+            &client,
+            "13WRNw4fmssJBvMqMnREwe1eCvUVXfnWXSXGcWXyVvAnQUF3D9R",
+            500_000,
+        )
+        .await
+        .expect("account");
+        assert_eq!(
+            account.address,
+            "13WRNw4fmssJBvMqMnREwe1eCvUVXfnWXSXGcWXyVvAnQUF3D9R"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "transactions")]
+    async fn get_rewards_between_valued() {
+        let client = Client::default();
+        let max_time = Utc::now();
+        let min_time = max_time - ChronoDuration::days(30);
+        let valued = accounts::get_rewards_between_valued(
+            &client,
+            "13WRNw4fmssJBvMqMnREwe1eCvUVXfnWXSXGcWXyVvAnQUF3D9R",
+            min_time,
+            max_time,
+        )
+        .await
+        .expect("valued rewards");
+        for (reward, usd) in &valued {
+            if let Some(usd) = usd {
+                let price = oracle::price_at_block(&client, reward.block)
+                    .await
+                    .expect("price");
+                assert_eq!(*usd, price * reward.amount.get_decimal());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "transactions")]
+    async fn reward_sum() {
+        let client = Client::default();
+        let max_time = Utc::now();
+        let min_time = max_time - ChronoDuration::days(30);
+        let sum = accounts::reward_sum(
+            &client,
+            "13WRNw4fmssJBvMqMnREwe1eCvUVXfnWXSXGcWXyVvAnQUF3D9R",
+            min_time,
+            max_time,
+            None,
+        )
+        .await
+        .expect("reward sum");
+        assert_eq!(sum.min_time, min_time);
+        assert!(sum.total.is_some());
+        assert!(sum.buckets.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "transactions")]
+    async fn reward_sum_bucketed() {
+        let client = Client::default();
+        let max_time = Utc::now();
+        let min_time = max_time - ChronoDuration::days(30);
+        let sum = accounts::reward_sum(
+            &client,
+            "13WRNw4fmssJBvMqMnREwe1eCvUVXfnWXSXGcWXyVvAnQUF3D9R",
+            min_time,
+            max_time,
+            Some("day"),
+        )
+        .await
+        .expect("bucketed reward sum");
+        assert_eq!(sum.min_time, min_time);
+        assert!(sum.total.is_none());
+        assert!(!sum.buckets.is_empty());
+    }
+
     #[test]
     async fn ouis() {
         let client = Client::default();
@@ -0,0 +1,75 @@
+use crate::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+/// A block on the blockchain
+pub struct Block {
+    /// The block height
+    pub height: u64,
+    /// The hash of the block
+    pub hash: String,
+    /// The unix timestamp the block was gossiped at
+    pub time: i64,
+}
+
+#[derive(Deserialize)]
+struct Height {
+    height: u64,
+}
+
+/// Get the current chain height
+pub async fn height(client: &Client) -> Result<u64> {
+    let result: Height = client.fetch("/blocks/height", NO_QUERY).await?;
+    Ok(result.height)
+}
+
+/// Get a specific block by its height
+pub async fn get(client: &Client, height: u64) -> Result<Block> {
+    client.fetch(&format!("/blocks/{}", height), NO_QUERY).await
+}
+
+/// Find the block height that was current at a given wall-clock time,
+/// by binary search over the chain's height range. Returns block 1 if
+/// `t` precedes genesis, and the current chain height if `t` is in the
+/// future. Issues O(log height) requests.
+pub async fn at_time(client: &Client, t: DateTime<Utc>) -> Result<u64> {
+    let target = t.timestamp();
+
+    let mut hi = height(client).await?;
+    let hi_block = get(client, hi).await?;
+    if hi_block.time <= target {
+        return Ok(hi);
+    }
+
+    let mut lo = 1;
+    let lo_block = get(client, lo).await?;
+    if lo_block.time >= target {
+        return Ok(lo);
+    }
+
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        let block = get(client, mid).await?;
+        if block.time <= target {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    Ok(lo)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tokio::test;
+
+    #[test]
+    async fn at_time() {
+        let client = Client::default();
+        let t = Utc::now() - ChronoDuration::days(30);
+        let height = blocks::at_time(&client, t).await.expect("height");
+        assert!(height > 0);
+    }
+}
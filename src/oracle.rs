@@ -0,0 +1,83 @@
+use crate::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+/// A HNT price reported by the Helium oracle, active from the given
+/// block height onward until the next reported price.
+pub struct Price {
+    /// The block height at which this price became active
+    pub block: u64,
+    /// The HNT price in USD, as an integer with 8 implied decimals
+    pub price: u64,
+}
+
+impl Price {
+    /// The price as a USD-per-HNT decimal value
+    pub fn usd(&self) -> Decimal {
+        Decimal::new(self.price as i64, 8)
+    }
+}
+
+/// Get the full history of oracle prices known to the API, oldest first
+pub fn all(client: &Client) -> Stream<Price> {
+    client.fetch_stream("/oracle/prices", NO_QUERY)
+}
+
+/// Get the oracle price active at a specific block height
+pub async fn price_at_block(client: &Client, block: u64) -> Result<Decimal> {
+    let price: Price = client
+        .fetch(&format!("/oracle/prices/{}", block), NO_QUERY)
+        .await?;
+    Ok(price.usd())
+}
+
+/// A cached, block-ordered oracle price series, used to look up the
+/// price active at a given block without issuing one request per
+/// lookup.
+pub struct PriceHistory(Vec<Price>);
+
+impl PriceHistory {
+    /// Fetch and cache the full oracle price series
+    pub async fn new(client: &Client) -> Result<Self> {
+        let mut prices = all(client).into_vec().await?;
+        prices.sort_by_key(|price| price.block);
+        Ok(Self(prices))
+    }
+
+    /// Find the price active at the given block, i.e. the most recent
+    /// price with a block height less than or equal to `block`. Returns
+    /// `None` if `block` precedes the first known oracle price.
+    pub fn price_at(&self, block: u64) -> Option<Decimal> {
+        let index = self.0.partition_point(|price| price.block <= block);
+        if index == 0 {
+            None
+        } else {
+            Some(self.0[index - 1].usd())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tokio::test;
+
+    #[test]
+    async fn price_at_block() {
+        let client = Client::default();
+        let price = oracle::price_at_block(&client, 500_000)
+            .await
+            .expect("price");
+        assert!(price > Decimal::new(0, 0));
+    }
+
+    #[test]
+    async fn price_history() {
+        let client = Client::default();
+        let history = oracle::PriceHistory::new(&client)
+            .await
+            .expect("price history");
+        assert!(history.price_at(500_000).is_some());
+    }
+}
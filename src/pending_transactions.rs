@@ -0,0 +1,91 @@
+use crate::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+/// The status of a transaction that has been submitted to the chain but
+/// has not necessarily been absorbed into a block yet.
+pub enum Status {
+    Pending,
+    Cleared,
+    Failed,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+/// A transaction that has been submitted for broadcast, along with its
+/// current status.
+pub struct PendingTxn {
+    /// The hash of the submitted transaction
+    pub hash: String,
+    /// The current status of the transaction
+    pub status: Status,
+}
+
+#[derive(Serialize)]
+struct SubmitRequest<'a> {
+    txn: &'a str,
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Base64-encode (standard alphabet, `=` padded) a signed transaction
+/// for the wire. Written out by hand rather than pulled in as a
+/// dependency, since encoding is the only thing we need it for.
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = *chunk.get(1).unwrap_or(&0);
+        let b2 = *chunk.get(2).unwrap_or(&0);
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            BASE64_ALPHABET[(b2 & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
+
+/// Submit a signed, serialized transaction for broadcast to the chain.
+/// `txn` is the raw, signed transaction bytes; they are base64-encoded
+/// before being sent. Relies on `Client::post` to issue the write,
+/// mirroring the GET-based `Client::fetch`/`Client::fetch_stream` used
+/// by the rest of this crate.
+#[cfg(feature = "transactions")]
+pub async fn submit(client: &Client, txn: &[u8]) -> Result<PendingTxn> {
+    let encoded = base64_encode(txn);
+    client
+        .post("/pending_transactions", &SubmitRequest { txn: &encoded })
+        .await
+}
+
+/// Get the status of a previously submitted transaction by its hash
+#[cfg(feature = "transactions")]
+pub async fn get(client: &Client, hash: &str) -> Result<PendingTxn> {
+    client
+        .fetch(&format!("/pending_transactions/{}", hash), NO_QUERY)
+        .await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn base64_encode() {
+        assert_eq!(super::base64_encode(b""), "");
+        assert_eq!(super::base64_encode(b"f"), "Zg==");
+        assert_eq!(super::base64_encode(b"fo"), "Zm8=");
+        assert_eq!(super::base64_encode(b"foo"), "Zm9v");
+        assert_eq!(super::base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+}